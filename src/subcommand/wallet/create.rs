@@ -0,0 +1,106 @@
+use {super::*, bip39::Mnemonic};
+
+#[derive(Debug, Parser, Clone)]
+pub(crate) struct Create {
+  #[arg(
+    long,
+    default_value = "",
+    help = "Use <PASSPHRASE> when deriving wallet"
+  )]
+  pub(crate) passphrase: String,
+  #[arg(
+    long,
+    help = "Create a watch-only wallet whose keys live on the hardware signing device with master key fingerprint <HARDWARE>, instead of generating a hot key. Requires an HWI-compatible binary on `$PATH`."
+  )]
+  pub(crate) hardware: Option<Fingerprint>,
+  #[arg(
+    long,
+    conflicts_with = "hardware",
+    value_name = "K-OF-N",
+    help = "Create a <K-OF-N> taproot multisig wallet, e.g. `2-of-3`. The local seed supplies one key; the remaining n - 1 must be supplied with `--cosigner`."
+  )]
+  pub(crate) multisig: Option<Threshold>,
+  #[arg(
+    long,
+    requires = "multisig",
+    value_name = "XPUB",
+    help = "Add <XPUB> (in `[fingerprint/path]xpub...` form) as a multisig cosigner. May be given multiple times."
+  )]
+  pub(crate) cosigner: Vec<DescriptorPublicKey>,
+}
+
+/// A `k-of-n` multisig threshold, parsed from strings like `2-of-3`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Threshold {
+  pub(crate) k: usize,
+  pub(crate) n: usize,
+}
+
+impl FromStr for Threshold {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (k, n) = s
+      .split_once("-of-")
+      .with_context(|| format!("invalid threshold `{s}`, expected e.g. `2-of-3`"))?;
+
+    let k: usize = k.parse().context("invalid threshold numerator")?;
+    let n: usize = n.parse().context("invalid threshold denominator")?;
+
+    ensure!(k > 0 && k <= n, "threshold must satisfy 0 < k <= n");
+
+    Ok(Self { k, n })
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub mnemonic: Option<Mnemonic>,
+  pub passphrase: Option<String>,
+  pub fingerprint: Option<Fingerprint>,
+}
+
+impl Create {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    if let Some(fingerprint) = self.hardware {
+      let derivation_path = Wallet::account_derivation_path(wallet.chain().network());
+      let xpub = hwi::get_xpub(fingerprint, &derivation_path)?;
+
+      wallet.initialize_with_hardware_wallet(fingerprint, xpub)?;
+
+      return Ok(Box::new(Output {
+        mnemonic: None,
+        passphrase: None,
+        fingerprint: Some(fingerprint),
+      }));
+    }
+
+    let mut entropy = [0; 16];
+    rand::thread_rng().fill_bytes(&mut entropy);
+
+    let mnemonic = Mnemonic::from_entropy(&entropy)?;
+
+    let seed = mnemonic.to_seed(self.passphrase.clone());
+
+    if let Some(threshold) = self.multisig {
+      ensure!(
+        self.cosigner.len() == threshold.n - 1,
+        "`--multisig {}-of-{}` requires {} `--cosigner` keys, got {}",
+        threshold.k,
+        threshold.n,
+        threshold.n - 1,
+        self.cosigner.len(),
+      );
+
+      wallet.initialize_multisig(seed, threshold.k, self.cosigner)?;
+    } else {
+      wallet.initialize(seed)?;
+    }
+
+    Ok(Box::new(Output {
+      mnemonic: Some(mnemonic),
+      passphrase: Some(self.passphrase),
+      fingerprint: None,
+    }))
+  }
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+/// A thin client for the subset of the Esplora REST API (https://github.com/Blockstream/esplora/blob/master/API.md)
+/// the wallet needs: UTXO lookups by address, raw transaction fetches, and broadcast. This lets
+/// `ord wallet` run against a remote Esplora/Electrum-style service instead of a full Bitcoin
+/// Core node.
+pub(crate) struct EsploraClient {
+  url: Url,
+  client: reqwest::blocking::Client,
+}
+
+#[derive(Deserialize)]
+struct Utxo {
+  txid: Txid,
+  vout: u32,
+  value: u64,
+}
+
+impl EsploraClient {
+  pub(crate) fn new(url: Url) -> Self {
+    Self {
+      url,
+      client: reqwest::blocking::Client::new(),
+    }
+  }
+
+  fn get(&self, path: &str) -> Result<reqwest::blocking::Response> {
+    let response = self.client.get(self.url.join(path)?).send()?;
+
+    if !response.status().is_success() {
+      bail!(
+        "esplora request to {path} failed with status {}",
+        response.status()
+      );
+    }
+
+    Ok(response)
+  }
+
+  /// Returns every unspent output paying one of `addresses`.
+  pub(crate) fn get_unspent_outputs(
+    &self,
+    addresses: &[Address],
+  ) -> Result<BTreeMap<OutPoint, Amount>> {
+    let mut utxos = BTreeMap::new();
+
+    for address in addresses {
+      let response = self.get(&format!("address/{address}/utxo"))?;
+
+      for utxo in response.json::<Vec<Utxo>>()? {
+        utxos.insert(
+          OutPoint::new(utxo.txid, utxo.vout),
+          Amount::from_sat(utxo.value),
+        );
+      }
+    }
+
+    Ok(utxos)
+  }
+
+  pub(crate) fn get_transaction(&self, txid: &Txid) -> Result<Transaction> {
+    let bytes = self.get(&format!("tx/{txid}/raw"))?.bytes()?;
+
+    Ok(bitcoin::consensus::deserialize(&bytes)?)
+  }
+
+  pub(crate) fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+    let hex = bitcoin::consensus::encode::serialize_hex(tx);
+
+    let response = self
+      .client
+      .post(self.url.join("tx")?)
+      .body(hex)
+      .send()?;
+
+    if !response.status().is_success() {
+      bail!(
+        "esplora rejected broadcast with status {}: {}",
+        response.status(),
+        response.text().unwrap_or_default()
+      );
+    }
+
+    response.text()?.trim().parse().context("esplora returned an invalid txid")
+  }
+
+  pub(crate) fn block_count(&self) -> Result<u64> {
+    Ok(self.get("blocks/tip/height")?.text()?.trim().parse()?)
+  }
+}
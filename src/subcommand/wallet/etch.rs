@@ -0,0 +1,148 @@
+use super::*;
+
+#[derive(Debug, Parser, Clone)]
+pub(crate) struct Etch {
+  #[arg(long, help = "Etch rune <RUNE>. May contain `.` or `•` spacers.")]
+  pub(crate) rune: SpacedRune,
+  #[arg(long, default_value = "0", help = "Allow <DIVISIBILITY> decimal places in amounts.")]
+  pub(crate) divisibility: u8,
+  #[arg(long, default_value = "0", help = "Premine <PREMINE> units of the rune to this wallet.")]
+  pub(crate) premine: u128,
+  #[arg(long, help = "Use <SYMBOL> as the rune's currency symbol.")]
+  pub(crate) symbol: Option<char>,
+  #[arg(long, help = "Allow minting up to <CAP> times, each for `--amount` units.")]
+  pub(crate) cap: Option<u128>,
+  #[arg(
+    long,
+    requires = "cap",
+    help = "Give each mint <AMOUNT> units of the rune."
+  )]
+  pub(crate) amount: Option<u128>,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vbyte.")]
+  pub(crate) fee_rate: FeeRate,
+  #[command(flatten)]
+  pub(crate) psbt: psbt::PsbtOptions,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub rune: SpacedRune,
+  pub transaction: Txid,
+}
+
+impl Etch {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    if self.psbt.sign(&wallet)? {
+      return Ok(Box::new(Empty {}));
+    }
+
+    if let Some(transaction) = self.psbt.broadcast(&wallet)? {
+      return Ok(Box::new(Output {
+        rune: self.rune,
+        transaction,
+      }));
+    }
+
+    let runestone = Runestone {
+      etching: Some(Etching {
+        divisibility: (self.divisibility > 0).then_some(self.divisibility),
+        premine: (self.premine > 0).then_some(self.premine),
+        rune: Some(self.rune.rune),
+        spacers: (self.rune.spacers > 0).then_some(self.rune.spacers),
+        symbol: self.symbol,
+        terms: self.cap.map(|cap| Terms {
+          cap: Some(cap),
+          amount: self.amount,
+          height: (None, None),
+          offset: (None, None),
+        }),
+        turbo: false,
+      }),
+      ..Default::default()
+    };
+
+    let unsigned_transaction =
+      Self::build_unsigned_transaction(&wallet, &runestone, self.fee_rate)?;
+
+    if self.psbt.dump(&wallet, &unsigned_transaction)? {
+      return Ok(Box::new(Empty {}));
+    }
+
+    let signed = wallet.sign_psbt(psbt::build_unsigned(&wallet, &unsigned_transaction)?)?;
+
+    let transaction = wallet.broadcast_psbt(signed)?;
+
+    Ok(Box::new(Output {
+      rune: self.rune,
+      transaction,
+    }))
+  }
+
+  /// Builds an unsigned transaction that funds `runestone`'s etching output and the wallet's
+  /// premine (if any) from the wallet's cardinal funds, greedily selecting inputs the same way
+  /// `mint::Mint::build_fanout_transaction` does.
+  fn build_unsigned_transaction(
+    wallet: &Wallet,
+    runestone: &Runestone,
+    fee_rate: FeeRate,
+  ) -> Result<Transaction> {
+    let locked_outputs = wallet.get_locked_outputs()?;
+    let runic_outputs = wallet.get_runic_outputs()?;
+
+    let spendable = wallet
+      .get_cardinal_outputs()?
+      .into_iter()
+      .filter(|(outpoint, _)| !locked_outputs.contains(outpoint) && !runic_outputs.contains(outpoint));
+
+    let premine_output = TxOut {
+      script_pubkey: wallet.get_change_address()?.script_pubkey(),
+      value: TransactionBuilder::TARGET_POSTAGE.to_sat(),
+    };
+
+    let mut inputs = Vec::new();
+    let mut total_in = 0;
+
+    for (outpoint, amount) in spendable {
+      inputs.push(outpoint);
+      total_in += amount.to_sat();
+
+      let mut tx = Transaction {
+        version: 2,
+        lock_time: LockTime::ZERO,
+        input: inputs
+          .iter()
+          .map(|outpoint| TxIn {
+            previous_output: *outpoint,
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: Witness::new(),
+          })
+          .collect(),
+        output: vec![
+          TxOut {
+            script_pubkey: runestone.encipher(),
+            value: 0,
+          },
+          premine_output.clone(),
+        ],
+      };
+
+      let fee = fee_rate.fee(tx.vsize().try_into().unwrap()).to_sat();
+
+      let Some(change) = total_in.checked_sub(premine_output.value + fee) else {
+        continue;
+      };
+
+      if change > 0 {
+        tx.output.push(TxOut {
+          script_pubkey: wallet.get_change_address()?.script_pubkey(),
+          value: change,
+        });
+      }
+
+      return Ok(tx);
+    }
+
+    bail!("wallet has insufficient cardinal funds to etch a rune");
+  }
+}
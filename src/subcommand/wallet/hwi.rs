@@ -0,0 +1,79 @@
+use {super::*, bitcoin::psbt::Psbt, std::process::Command};
+
+/// Name of the HWI-compatible binary invoked for every external-signer operation. HWI
+/// (https://github.com/bitcoin-core/HWI) exposes this same `signtx`/`getxpub` interface for
+/// Ledger, Trezor, Coldcard, and other supported devices, so shelling out to it is enough to
+/// support all of them rather than speaking each vendor's protocol directly.
+const HWI_BINARY: &str = "hwi";
+
+/// Asks the connected hardware wallet for the account-level xpub used to derive an `ord`
+/// wallet's taproot descriptors.
+pub(crate) fn get_xpub(fingerprint: Fingerprint, derivation_path: &DerivationPath) -> Result<ExtendedPubKey> {
+  let output = Command::new(HWI_BINARY)
+    .arg("--fingerprint")
+    .arg(fingerprint.to_string())
+    .arg("getxpub")
+    .arg(derivation_path.to_string())
+    .output()
+    .context("failed to run `hwi`, is it installed and on `$PATH`?")?;
+
+  if !output.status.success() {
+    bail!(
+      "`hwi getxpub` failed: {}",
+      String::from_utf8_lossy(&output.stderr)
+    );
+  }
+
+  #[derive(Deserialize)]
+  struct XpubResponse {
+    xpub: ExtendedPubKey,
+  }
+
+  let response: XpubResponse = serde_json::from_slice(&output.stdout)
+    .context("failed to parse `hwi getxpub` output")?;
+
+  Ok(response.xpub)
+}
+
+/// Hands an unsigned PSBT to the connected hardware wallet and returns it back with the
+/// device's signatures applied. The PSBT must already carry the BIP32 key-origin derivation
+/// paths for every input the device is expected to sign, so it can recognize which keys are
+/// its own.
+pub(crate) fn sign(fingerprint: Fingerprint, psbt: &Psbt) -> Result<Psbt> {
+  let output = Command::new(HWI_BINARY)
+    .arg("--fingerprint")
+    .arg(fingerprint.to_string())
+    .arg("signtx")
+    .arg(base64_encode(&psbt.serialize()))
+    .output()
+    .context("failed to run `hwi`, is it installed and on `$PATH`?")?;
+
+  if !output.status.success() {
+    bail!(
+      "`hwi signtx` failed: {}",
+      String::from_utf8_lossy(&output.stderr)
+    );
+  }
+
+  #[derive(Deserialize)]
+  struct SignResponse {
+    psbt: String,
+  }
+
+  let response: SignResponse =
+    serde_json::from_slice(&output.stdout).context("failed to parse `hwi signtx` output")?;
+
+  Psbt::deserialize(&base64_decode(&response.psbt)?).context("device returned an invalid PSBT")
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+  use base64::Engine;
+  base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+fn base64_decode(s: &str) -> Result<Vec<u8>> {
+  use base64::Engine;
+  base64::engine::general_purpose::STANDARD
+    .decode(s)
+    .context("device returned invalid base64")
+}
@@ -0,0 +1,269 @@
+use {
+  super::*,
+  bitcoin::{
+    blockdata::opcodes,
+    script::{Builder, PushBytesBuf},
+    secp256k1::{rand, KeyPair, Message, Secp256k1, XOnlyPublicKey},
+    sighash::{Prevouts, SighashCache, TapSighashType},
+    taproot::{LeafVersion, TaprootBuilder},
+  },
+};
+
+#[derive(Debug, Parser, Clone)]
+pub(crate) struct Inscribe {
+  #[arg(long, help = "Inscribe contents of <FILE>.")]
+  pub(crate) file: PathBuf,
+  #[arg(long, help = "Send created inscription to <DESTINATION>.")]
+  pub(crate) destination: Option<Address<NetworkUnchecked>>,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vbyte.")]
+  pub(crate) fee_rate: FeeRate,
+  #[command(
+    flatten,
+    next_help_heading = "Commit transaction PSBT options (the reveal transaction's key is \
+      generated fresh for this inscription and is never held by Core or an external signer, so \
+      only the commit transaction — an ordinary wallet spend — goes through this workflow)"
+  )]
+  pub(crate) psbt: psbt::PsbtOptions,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub commit: Txid,
+  pub reveal: Txid,
+}
+
+impl Inscribe {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    if self.psbt.sign(&wallet)? {
+      return Ok(Box::new(Empty {}));
+    }
+
+    if let Some(commit) = self.psbt.broadcast(&wallet)? {
+      bail!("commit transaction {commit} broadcast, but the reveal transaction depends on the fresh reveal key generated when the commit was built — re-run `ord wallet inscribe` from the start instead of `--broadcast-psbt`");
+    }
+
+    let secp = Secp256k1::new();
+
+    let reveal_key = KeyPair::new(&secp, &mut rand::thread_rng());
+
+    let content = fs::read(&self.file)
+      .with_context(|| format!("failed to read `{}`", self.file.display()))?;
+
+    let content_type = content_type(&self.file)?;
+
+    let reveal_script = Self::build_reveal_script(&reveal_key.x_only_public_key().0, &content_type, &content)?;
+
+    let taproot_spend_info = TaprootBuilder::new()
+      .add_leaf(0, reveal_script.clone())?
+      .finalize(&secp, reveal_key.x_only_public_key().0)
+      .map_err(|_| anyhow!("failed to build taproot commitment for inscription envelope"))?;
+
+    let commit_address = Address::p2tr_tweaked(taproot_spend_info.output_key(), wallet.chain().network());
+
+    let destination = match self.destination {
+      Some(destination) => destination.require_network(wallet.chain().network())?,
+      None => wallet.get_change_address()?,
+    };
+
+    const REVEAL_VSIZE_ESTIMATE: u64 = 200;
+
+    let reveal_fee = self.fee_rate.fee(REVEAL_VSIZE_ESTIMATE);
+    let reveal_output_value = TransactionBuilder::TARGET_POSTAGE + reveal_fee;
+
+    let unsigned_commit = Self::build_unsigned_commit(&wallet, &commit_address, reveal_output_value, self.fee_rate)?;
+
+    if self.psbt.dump(&wallet, &unsigned_commit)? {
+      return Ok(Box::new(Empty {}));
+    }
+
+    let signed_commit = wallet.sign_psbt(psbt::build_unsigned(&wallet, &unsigned_commit)?)?;
+
+    let commit = wallet.broadcast_psbt(signed_commit)?;
+
+    let reveal = Self::build_and_sign_reveal(
+      &secp,
+      &reveal_key,
+      &reveal_script,
+      &taproot_spend_info,
+      OutPoint::new(commit, 0),
+      reveal_output_value,
+      &destination,
+      wallet.chain().network(),
+    )?;
+
+    let reveal_txid = wallet.broadcast_transaction(&reveal)?;
+
+    Ok(Box::new(Output {
+      commit,
+      reveal: reveal_txid,
+    }))
+  }
+
+  /// Builds the tapscript ord's inscription envelope is revealed with: a plain signature check
+  /// against the fresh `reveal_key`, followed by the `OP_FALSE OP_IF ... OP_ENDIF` envelope
+  /// that carries the inscription's content type and body (chunked to fit the 520-byte push
+  /// limit). The envelope is only ever executed when the `OP_IF` branch is taken, so it costs
+  /// nothing at the script-interpreter level beyond the signature check.
+  fn build_reveal_script(public_key: &XOnlyPublicKey, content_type: &str, content: &[u8]) -> Result<ScriptBuf> {
+    let mut builder = Builder::new()
+      .push_slice(PushBytesBuf::try_from(public_key.serialize().to_vec())?)
+      .push_opcode(opcodes::all::OP_CHECKSIG)
+      .push_opcode(opcodes::OP_FALSE)
+      .push_opcode(opcodes::all::OP_IF)
+      .push_slice(PushBytesBuf::try_from(b"ord".to_vec())?)
+      .push_slice(PushBytesBuf::try_from(vec![1])?)
+      .push_slice(PushBytesBuf::try_from(content_type.as_bytes().to_vec())?)
+      .push_slice(PushBytesBuf::try_from(vec![0])?);
+
+    for chunk in content.chunks(520) {
+      builder = builder.push_slice(PushBytesBuf::try_from(chunk.to_vec())?);
+    }
+
+    Ok(builder.push_opcode(opcodes::all::OP_ENDIF).into_script())
+  }
+
+  /// Builds an unsigned transaction funding `commit_address` with `reveal_output_value` from
+  /// the wallet's cardinal UTXOs, greedily selecting inputs the same way
+  /// `mint::Mint::build_fanout_transaction` and `etch::Etch::build_unsigned_transaction` do.
+  fn build_unsigned_commit(
+    wallet: &Wallet,
+    commit_address: &Address,
+    reveal_output_value: Amount,
+    fee_rate: FeeRate,
+  ) -> Result<Transaction> {
+    let locked_outputs = wallet.get_locked_outputs()?;
+    let runic_outputs = wallet.get_runic_outputs()?;
+
+    let spendable = wallet
+      .get_cardinal_outputs()?
+      .into_iter()
+      .filter(|(outpoint, _)| !locked_outputs.contains(outpoint) && !runic_outputs.contains(outpoint));
+
+    let mut inputs = Vec::new();
+    let mut total_in = 0;
+
+    for (outpoint, amount) in spendable {
+      inputs.push(outpoint);
+      total_in += amount.to_sat();
+
+      let mut tx = Transaction {
+        version: 2,
+        lock_time: LockTime::ZERO,
+        input: inputs
+          .iter()
+          .map(|outpoint| TxIn {
+            previous_output: *outpoint,
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: Witness::new(),
+          })
+          .collect(),
+        output: vec![TxOut {
+          script_pubkey: commit_address.script_pubkey(),
+          value: reveal_output_value.to_sat(),
+        }],
+      };
+
+      let fee = fee_rate.fee(tx.vsize().try_into().unwrap()).to_sat();
+
+      let Some(change) = total_in.checked_sub(reveal_output_value.to_sat() + fee) else {
+        continue;
+      };
+
+      if change > 0 {
+        tx.output.push(TxOut {
+          script_pubkey: wallet.get_change_address()?.script_pubkey(),
+          value: change,
+        });
+      }
+
+      return Ok(tx);
+    }
+
+    bail!("wallet has insufficient cardinal funds to fund the inscription's commit transaction");
+  }
+
+  /// Builds and signs the reveal transaction, spending the commit output's script path with
+  /// the fresh `reveal_key` generated for this one inscription. This key was never imported
+  /// into Core or handed to an external signer, so it's signed in-process rather than through
+  /// `Wallet::sign_psbt`.
+  fn build_and_sign_reveal(
+    secp: &Secp256k1<bitcoin::secp256k1::All>,
+    reveal_key: &KeyPair,
+    reveal_script: &ScriptBuf,
+    taproot_spend_info: &bitcoin::taproot::TaprootSpendInfo,
+    commit_outpoint: OutPoint,
+    commit_value: Amount,
+    destination: &Address,
+    network: Network,
+  ) -> Result<Transaction> {
+    let mut reveal_tx = Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: vec![TxIn {
+        previous_output: commit_outpoint,
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+        witness: Witness::new(),
+      }],
+      output: vec![TxOut {
+        script_pubkey: destination.script_pubkey(),
+        value: TransactionBuilder::TARGET_POSTAGE.to_sat(),
+      }],
+    };
+
+    let commit_script_pubkey =
+      Address::p2tr_tweaked(taproot_spend_info.output_key(), network).script_pubkey();
+
+    let mut sighash_cache = SighashCache::new(&reveal_tx);
+
+    let sighash = sighash_cache.taproot_script_spend_signature_hash(
+      0,
+      &Prevouts::All(&[TxOut {
+        script_pubkey: commit_script_pubkey,
+        value: commit_value.to_sat(),
+      }]),
+      bitcoin::taproot::TapLeafHash::from_script(reveal_script, LeafVersion::TapScript),
+      TapSighashType::Default,
+    )?;
+
+    let signature = secp.sign_schnorr(&Message::from_slice(sighash.as_ref())?, reveal_key);
+
+    let control_block = taproot_spend_info
+      .control_block(&(reveal_script.clone(), LeafVersion::TapScript))
+      .context("failed to build control block for inscription reveal")?;
+
+    reveal_tx.input[0].witness.push(signature.as_ref());
+    reveal_tx.input[0].witness.push(reveal_script.as_bytes());
+    reveal_tx.input[0].witness.push(control_block.serialize());
+
+    Ok(reveal_tx)
+  }
+}
+
+/// Guesses an inscription's content type from its file extension. `ord` upstream uses a MIME
+/// database for this; this crate only needs to round-trip the handful of types its own test
+/// fixtures use.
+fn content_type(path: &Path) -> Result<String> {
+  let extension = path
+    .extension()
+    .and_then(|extension| extension.to_str())
+    .with_context(|| format!("`{}` has no file extension", path.display()))?;
+
+  Ok(
+    match extension.to_lowercase().as_str() {
+      "txt" => "text/plain;charset=utf-8",
+      "html" => "text/html;charset=utf-8",
+      "json" => "application/json",
+      "png" => "image/png",
+      "jpg" | "jpeg" => "image/jpeg",
+      "gif" => "image/gif",
+      "svg" => "image/svg+xml",
+      "webp" => "image/webp",
+      "mp4" => "video/mp4",
+      "pdf" => "application/pdf",
+      other => bail!("unknown content type for file extension `{other}`"),
+    }
+    .into(),
+  )
+}
@@ -0,0 +1,360 @@
+use super::*;
+
+#[derive(Debug, Parser, Clone)]
+pub(crate) struct Mint {
+  #[arg(long, help = "Mint <RUNE>.")]
+  pub(crate) rune: SpacedRune,
+  #[arg(long, help = "Mint <COUNT> times in total.")]
+  pub(crate) count: u64,
+  #[arg(
+    long,
+    default_value = "1",
+    help = "Fund at most <PER_TX> mints from each fan-out output. Every mint is its own transaction; the mints funded by one output are chained one after another, while mints funded by different outputs can confirm in parallel."
+  )]
+  pub(crate) per_tx: u64,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vbyte.")]
+  pub(crate) fee_rate: FeeRate,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub fanout: Txid,
+  pub mints: Vec<Txid>,
+  pub requested: u64,
+  pub completed: u64,
+}
+
+impl Mint {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    ensure!(self.count > 0, "`--count` must be greater than zero");
+    ensure!(self.per_tx > 0, "`--per-tx` must be greater than zero");
+
+    let (rune_id, entry, _parent) = wallet
+      .get_rune(self.rune.rune)?
+      .with_context(|| format!("rune {} has not been etched", self.rune))?;
+
+    let terms = entry
+      .terms
+      .with_context(|| format!("rune {} is not mintable", self.rune))?;
+
+    let remaining = terms
+      .cap
+      .map(|cap| cap.saturating_sub(entry.mints))
+      .unwrap_or(u128::MAX);
+
+    ensure!(
+      u128::from(self.count) <= remaining,
+      "requested {} mints but only {remaining} remain under the rune's cap",
+      self.count,
+    );
+
+    let height = wallet.block_count()?;
+
+    let (start, end) = Self::mint_window(&entry, &terms);
+
+    if let Some(start) = start {
+      ensure!(
+        height >= start,
+        "rune {} is not mintable until block {start}, current height is {height}",
+        self.rune,
+      );
+    }
+
+    if let Some(end) = end {
+      ensure!(
+        height < end,
+        "rune {} minting ended at block {end}, current height is {height}",
+        self.rune,
+      );
+    }
+
+    let batches = Self::batch_sizes(self.count, self.per_tx);
+
+    let outputs = batches
+      .iter()
+      .map(|&mints_in_batch| Self::batch_funding(mints_in_batch, self.fee_rate))
+      .collect::<Vec<Amount>>();
+
+    let fanout_tx = Self::build_fanout_transaction(&wallet, &outputs, self.fee_rate)?;
+
+    let fanout_txid = wallet.broadcast_transaction(&fanout_tx)?;
+
+    let mut mints = Vec::new();
+    let mut completed = 0;
+
+    for (vout, &mints_in_batch) in batches.iter().enumerate() {
+      let funding_outpoint = OutPoint::new(fanout_txid, u32::try_from(vout).unwrap());
+
+      match Self::mint_batch(&wallet, rune_id, funding_outpoint, mints_in_batch, self.fee_rate) {
+        Ok(txids) => {
+          completed += txids.len() as u64;
+          mints.extend(txids);
+        }
+        Err(err) => {
+          eprintln!("batch funded by {funding_outpoint} failed: {err}");
+        }
+      }
+    }
+
+    Ok(Box::new(Output {
+      fanout: fanout_txid,
+      mints,
+      requested: self.count,
+      completed,
+    }))
+  }
+
+  /// Roughly what a single-input, single-runestone-output mint transaction weighs; used only to
+  /// size the fan-out funding, so it errs generous rather than exact.
+  const MINT_VSIZE_ESTIMATE: u64 = 200;
+
+  /// Roughly what a single fan-out input or output adds to a transaction's weight; used only to
+  /// size the fan-out's own fee, so it errs generous rather than exact.
+  const FANOUT_BASE_VSIZE: u64 = 16;
+  const FANOUT_INPUT_VSIZE: u64 = 58;
+  const FANOUT_OUTPUT_VSIZE: u64 = 43;
+
+  /// Resolves the rune's mint window to absolute block heights: `terms.height` is already
+  /// absolute, `terms.offset` is relative to the etching's block, and when both bounds are
+  /// given the tighter of the two wins — matching the rules the index itself uses to decide
+  /// whether a mint is valid.
+  fn mint_window(entry: &RuneEntry, terms: &Terms) -> (Option<u64>, Option<u64>) {
+    let start = match (terms.height.0, terms.offset.0) {
+      (Some(height), Some(offset)) => Some(height.max(entry.block + offset)),
+      (Some(height), None) => Some(height),
+      (None, Some(offset)) => Some(entry.block + offset),
+      (None, None) => None,
+    };
+
+    let end = match (terms.height.1, terms.offset.1) {
+      (Some(height), Some(offset)) => Some(height.min(entry.block + offset)),
+      (Some(height), None) => Some(height),
+      (None, Some(offset)) => Some(entry.block + offset),
+      (None, None) => None,
+    };
+
+    (start, end)
+  }
+
+  /// Splits `count` mints into batches of at most `per_tx`, e.g. `batch_sizes(10, 3)` returns
+  /// `[3, 3, 3, 1]`. The result's length is how many fan-out outputs `mint_batch` needs, one per
+  /// batch, so that batches don't contend for the same UTXO and can confirm in parallel.
+  fn batch_sizes(count: u64, per_tx: u64) -> Vec<u64> {
+    let mut remaining = count;
+    let mut sizes = Vec::new();
+
+    while remaining > 0 {
+      let size = remaining.min(per_tx);
+      sizes.push(size);
+      remaining -= size;
+    }
+
+    sizes
+  }
+
+  /// How much a single fan-out output needs to hold to fund `mints_in_batch` chained mint
+  /// transactions, each paying postage plus a fee on top of what it forwards to the next.
+  fn batch_funding(mints_in_batch: u64, fee_rate: FeeRate) -> Amount {
+    let per_mint = TransactionBuilder::TARGET_POSTAGE + fee_rate.fee(Self::MINT_VSIZE_ESTIMATE);
+
+    Amount::from_sat(per_mint.to_sat() * mints_in_batch)
+  }
+
+  /// Builds and signs a single transaction that splits the wallet's cardinal funds into one
+  /// output per element of `outputs`, each sized by the caller to fund one `mint_batch` run.
+  fn build_fanout_transaction(
+    wallet: &Wallet,
+    outputs: &[Amount],
+    fee_rate: FeeRate,
+  ) -> Result<Transaction> {
+    let locked_outputs = wallet.get_locked_outputs()?;
+    let runic_outputs = wallet.get_runic_outputs()?;
+
+    let spendable = wallet
+      .get_cardinal_outputs()?
+      .into_iter()
+      .filter(|(outpoint, _)| !locked_outputs.contains(outpoint) && !runic_outputs.contains(outpoint));
+
+    let total_out = outputs.iter().map(Amount::to_sat).sum::<u64>();
+
+    let mut inputs = Vec::new();
+    let mut total_in = 0;
+
+    for (outpoint, amount) in spendable {
+      inputs.push(outpoint);
+      total_in += amount.to_sat();
+
+      let estimated_vsize = Self::FANOUT_BASE_VSIZE
+        + u64::try_from(inputs.len()).unwrap() * Self::FANOUT_INPUT_VSIZE
+        + u64::try_from(outputs.len() + 1).unwrap() * Self::FANOUT_OUTPUT_VSIZE;
+
+      let fee = fee_rate.fee(estimated_vsize).to_sat();
+
+      if total_in >= total_out + fee {
+        let mut tx = Transaction {
+          version: 2,
+          lock_time: LockTime::ZERO,
+          input: inputs
+            .iter()
+            .map(|outpoint| TxIn {
+              previous_output: *outpoint,
+              script_sig: ScriptBuf::new(),
+              sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+              witness: Witness::new(),
+            })
+            .collect(),
+          output: outputs
+            .iter()
+            .map(|amount| -> Result<TxOut> {
+              Ok(TxOut {
+                script_pubkey: wallet.get_change_address()?.script_pubkey(),
+                value: amount.to_sat(),
+              })
+            })
+            .collect::<Result<Vec<TxOut>>>()?,
+        };
+
+        let change = total_in - total_out - fee;
+
+        if change > 0 {
+          tx.output.push(TxOut {
+            script_pubkey: wallet.get_change_address()?.script_pubkey(),
+            value: change,
+          });
+        }
+
+        let signed = wallet
+          .bitcoin_client()?
+          .sign_raw_transaction_with_wallet(&tx, None, None)?
+          .hex;
+
+        return Ok(bitcoin::consensus::deserialize(&signed)?);
+      }
+    }
+
+    bail!(
+      "wallet has insufficient cardinal funds to fan out {} mint batches",
+      outputs.len()
+    );
+  }
+
+  /// Spends `funding_outpoint` with `mints_in_batch` sequential mint transactions, each minting
+  /// `rune_id` once and passing its change along to the next, so the batch only ever needs the
+  /// one UTXO handed to it by the fan-out transaction.
+  fn mint_batch(
+    wallet: &Wallet,
+    rune_id: RuneId,
+    mut funding_outpoint: OutPoint,
+    mints_in_batch: u64,
+    fee_rate: FeeRate,
+  ) -> Result<Vec<Txid>> {
+    let mut txids = Vec::new();
+
+    for _ in 0..mints_in_batch {
+      let runestone = Runestone {
+        mint: Some(rune_id),
+        ..Default::default()
+      };
+
+      let recipient = wallet.get_change_address()?;
+
+      let input_value = wallet
+        .get_raw_transaction(&funding_outpoint.txid)?
+        .output[usize::try_from(funding_outpoint.vout).unwrap()]
+      .value;
+
+      let mut tx = Transaction {
+        version: 2,
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+          previous_output: funding_outpoint,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness: Witness::new(),
+        }],
+        output: vec![
+          TxOut {
+            script_pubkey: runestone.encipher(),
+            value: 0,
+          },
+          TxOut {
+            script_pubkey: recipient.script_pubkey(),
+            value: TransactionBuilder::TARGET_POSTAGE.to_sat(),
+          },
+        ],
+      };
+
+      let fee = fee_rate.fee(tx.vsize().try_into().unwrap()).to_sat();
+
+      let change = input_value
+        .checked_sub(TransactionBuilder::TARGET_POSTAGE.to_sat() + fee)
+        .context("funding output too small to cover postage and fee")?;
+
+      if change > 0 {
+        tx.output.push(TxOut {
+          script_pubkey: wallet.get_change_address()?.script_pubkey(),
+          value: change,
+        });
+      }
+
+      let signed = wallet
+        .bitcoin_client()?
+        .sign_raw_transaction_with_wallet(&tx, None, None)?
+        .hex;
+
+      let tx: Transaction = bitcoin::consensus::deserialize(&signed)?;
+
+      let txid = wallet.broadcast_transaction(&tx)?;
+
+      funding_outpoint = OutPoint::new(txid, if change > 0 { 2 } else { u32::MAX });
+      txids.push(txid);
+
+      if change == 0 {
+        break;
+      }
+    }
+
+    Ok(txids)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn batch_sizes_divides_evenly() {
+    assert_eq!(Mint::batch_sizes(9, 3), vec![3, 3, 3]);
+  }
+
+  #[test]
+  fn batch_sizes_carries_remainder_in_final_batch() {
+    assert_eq!(Mint::batch_sizes(10, 3), vec![3, 3, 3, 1]);
+  }
+
+  #[test]
+  fn batch_sizes_puts_single_mint_below_per_tx_in_one_batch() {
+    assert_eq!(Mint::batch_sizes(1, 3), vec![1]);
+  }
+
+  #[test]
+  fn batch_funding_scales_with_batch_size() {
+    let fee_rate = FeeRate::try_from(1.0).unwrap();
+
+    let one = Mint::batch_funding(1, fee_rate);
+    let three = Mint::batch_funding(3, fee_rate);
+
+    assert_eq!(three, Amount::from_sat(one.to_sat() * 3));
+  }
+
+  #[test]
+  fn batch_funding_covers_postage_and_fee_for_every_chained_mint() {
+    let fee_rate = FeeRate::try_from(1.0).unwrap();
+
+    let funding = Mint::batch_funding(4, fee_rate);
+
+    let per_mint = TransactionBuilder::TARGET_POSTAGE + fee_rate.fee(Mint::MINT_VSIZE_ESTIMATE);
+
+    assert_eq!(funding, per_mint * 4);
+  }
+}
@@ -0,0 +1,339 @@
+use {
+  super::*,
+  bitcoin::{
+    bip32::KeySource,
+    psbt::{Input, Psbt},
+    secp256k1::{Secp256k1, XOnlyPublicKey},
+    taproot::{ControlBlock, LeafVersion, TapLeafHash},
+    ScriptBuf, Transaction, TxOut,
+  },
+};
+
+/// Extracts the output key from a taproot (`OP_1 <32-byte-key>`) script pubkey, or `None` if
+/// `script_pubkey` isn't taproot.
+fn tap_output_key(script_pubkey: &ScriptBuf) -> Option<XOnlyPublicKey> {
+  if !script_pubkey.is_p2tr() {
+    return None;
+  }
+
+  XOnlyPublicKey::from_slice(&script_pubkey.as_bytes()[2..34]).ok()
+}
+
+/// Everything a signer needs to sign for one taproot PSBT input: the untweaked internal key
+/// that BIP-371 requires `tap_internal_key` to hold (never the on-chain, tweaked output key),
+/// and the BIP32 origin of every key that can sign for this specific output — the exact
+/// `/change/index` Core actually derived it at, not just the wallet's shared account path. For
+/// a script-path (`multi_a`) output this also carries the leaf script and control block, and
+/// every cosigner's key is tagged with that leaf's hash so each cosigner's signer can tell
+/// which key, and which leaf, it's being asked to sign for.
+struct TapInfo {
+  internal_key: XOnlyPublicKey,
+  key_origins: BTreeMap<XOnlyPublicKey, (Vec<TapLeafHash>, KeySource)>,
+  leaf_script: Option<(ScriptBuf, ControlBlock)>,
+}
+
+/// Looks up, via Core's `getaddressinfo`, the exact descriptor and derivation index that
+/// produced `script_pubkey`, and from that derives the real taproot spending information for
+/// the output. Returns `None` if Core doesn't recognize the output as one of this wallet's own
+/// taproot outputs.
+fn derive_tap_info(wallet: &Wallet, script_pubkey: &ScriptBuf) -> Result<Option<TapInfo>> {
+  if tap_output_key(script_pubkey).is_none() {
+    return Ok(None);
+  }
+
+  let client = wallet.bitcoin_client()?;
+
+  let address = Address::from_script(script_pubkey, wallet.chain().network())?;
+
+  #[derive(Deserialize)]
+  struct AddressInfo {
+    desc: Option<String>,
+    hdkeypath: Option<String>,
+  }
+
+  let info: AddressInfo = client.call(
+    "getaddressinfo",
+    &[serde_json::Value::String(address.to_string())],
+  )?;
+
+  let (Some(desc), Some(hdkeypath)) = (info.desc, info.hdkeypath) else {
+    return Ok(None);
+  };
+
+  // `desc` is this one address's fully-derived descriptor, e.g.
+  // `tr([fp/86'/0'/0']xpub.../1/3)#checksum` — the `/change/index` suffix this output was
+  // actually derived at is already substituted in, so parsing `hdkeypath`'s last component is
+  // enough to know which index to re-derive at below.
+  let index: u32 = hdkeypath
+    .rsplit('/')
+    .next()
+    .with_context(|| format!("unexpected hdkeypath `{hdkeypath}`"))?
+    .parse()?;
+
+  let descriptor_str = desc.split('#').next().unwrap();
+
+  let wildcard_descriptor = Descriptor::<DescriptorPublicKey>::from_str(descriptor_str)?;
+
+  let secp = Secp256k1::new();
+
+  let Descriptor::Tr(derived_tr) = wildcard_descriptor.derived_descriptor(&secp, index)? else {
+    bail!("{script_pubkey} is not a taproot descriptor");
+  };
+
+  let internal_key = XOnlyPublicKey::from(derived_tr.internal_key().inner);
+
+  let Descriptor::Tr(wildcard_tr) = &wildcard_descriptor else {
+    unreachable!("already matched Descriptor::Tr above");
+  };
+
+  let mut key_origins = BTreeMap::new();
+
+  // A plain `tr(key)` wallet has no script tree: the one key signs the key path, tagged with
+  // no leaf hashes. A `tr(NUMS, multi_a(k, ...))` multisig wallet instead has every participant
+  // key sign the single `multi_a` leaf, so each needs the leaf hash attached.
+  if wildcard_tr.tap_tree().is_none() {
+    let key = wildcard_tr.internal_key();
+
+    let origin = (
+      key.master_fingerprint(),
+      key
+        .full_derivation_path()
+        .context("descriptor key is missing a BIP32 derivation path")?,
+    );
+
+    key_origins.insert(internal_key, (Vec::new(), origin));
+
+    return Ok(Some(TapInfo {
+      internal_key,
+      key_origins,
+      leaf_script: None,
+    }));
+  }
+
+  // A `multi_a` wallet has exactly one script leaf, so the wildcard descriptor (for origins)
+  // and the index-derived descriptor (for concrete signing keys) walk it in the same order.
+  let (_, wildcard_miniscript) = wildcard_tr
+    .iter_scripts()
+    .next()
+    .context("multisig descriptor has no script leaf")?;
+
+  let (_, derived_miniscript) = derived_tr
+    .iter_scripts()
+    .next()
+    .context("multisig descriptor has no script leaf")?;
+
+  let leaf_script = derived_miniscript.encode();
+  let leaf_hash = TapLeafHash::from_script(&leaf_script, LeafVersion::TapScript);
+
+  for (wildcard_key, derived_key) in wildcard_miniscript
+    .iter_pk()
+    .zip(derived_miniscript.iter_pk())
+  {
+    let origin = (
+      wildcard_key.master_fingerprint(),
+      wildcard_key
+        .full_derivation_path()
+        .context("cosigner key is missing a BIP32 derivation path")?,
+    );
+
+    key_origins.insert(
+      XOnlyPublicKey::from(derived_key.inner),
+      (vec![leaf_hash], origin),
+    );
+  }
+
+  let control_block = derived_tr
+    .spend_info()
+    .control_block(&(leaf_script.clone(), LeafVersion::TapScript))
+    .context("failed to build control block for multisig leaf")?;
+
+  Ok(Some(TapInfo {
+    internal_key,
+    key_origins,
+    leaf_script: Some((leaf_script, control_block)),
+  }))
+}
+
+/// Flags shared by every spending command (`send`, `inscribe`, `etch`) that let the wallet
+/// operate air-gapped: instead of asking Core to sign and broadcast in one step, the unsigned
+/// transaction is exported as a PSBT for an offline machine to sign, and a signed PSBT is later
+/// imported back in and broadcast.
+#[derive(Debug, Parser, Clone, Default)]
+pub(crate) struct PsbtOptions {
+  #[arg(
+    long,
+    conflicts_with_all = ["sign_psbt", "broadcast_psbt"],
+    help = "Write the unsigned transaction as a base64 PSBT to <DUMP_PSBT> instead of signing and broadcasting it."
+  )]
+  pub(crate) dump_psbt: Option<PathBuf>,
+  #[arg(
+    long,
+    conflicts_with_all = ["dump_psbt", "broadcast_psbt"],
+    help = "Sign the base64 PSBT in <SIGN_PSBT> (as written by `--dump-psbt`) and print the signed PSBT to stdout, instead of building a new transaction. Signing goes through Core's own keys, or through `hwi::sign` if this wallet's keys live on a hardware signer; for a multisig wallet, run this once per cosigner and feed each cosigner's output back in as the next cosigner's <SIGN_PSBT>."
+  )]
+  pub(crate) sign_psbt: Option<PathBuf>,
+  #[arg(
+    long,
+    conflicts_with_all = ["dump_psbt", "sign_psbt"],
+    help = "Finalize and broadcast the base64 PSBT in <BROADCAST_PSBT>, signed by an offline `--dump-psbt`/`--sign-psbt` round, instead of building a new transaction."
+  )]
+  pub(crate) broadcast_psbt: Option<PathBuf>,
+}
+
+impl PsbtOptions {
+  /// If `--dump-psbt` was given, writes `tx` out as an unsigned PSBT and returns `true` to tell
+  /// the caller not to sign or broadcast anything this run.
+  pub(crate) fn dump(&self, wallet: &Wallet, tx: &Transaction) -> Result<bool> {
+    let Some(path) = &self.dump_psbt else {
+      return Ok(false);
+    };
+
+    let psbt = build_unsigned(wallet, tx)?;
+
+    fs::write(path, encode(&psbt))
+      .with_context(|| format!("failed to write PSBT to {}", path.display()))?;
+
+    Ok(true)
+  }
+
+  /// If `--sign-psbt` was given, signs the PSBT found at that path and prints the result to
+  /// stdout, returning `true` to tell the caller not to build or broadcast anything this run.
+  pub(crate) fn sign(&self, wallet: &Wallet) -> Result<bool> {
+    let Some(path) = &self.sign_psbt else {
+      return Ok(false);
+    };
+
+    let contents = fs::read_to_string(path)
+      .with_context(|| format!("failed to read PSBT from {}", path.display()))?;
+
+    let signed = wallet.sign_psbt(decode(contents.trim())?)?;
+
+    println!("{}", encode(&signed));
+
+    Ok(true)
+  }
+
+  /// If `--broadcast-psbt` was given, finalizes and broadcasts the PSBT found at that path and
+  /// returns its txid, short-circuiting the caller's usual build-sign-broadcast flow.
+  pub(crate) fn broadcast(&self, wallet: &Wallet) -> Result<Option<Txid>> {
+    let Some(path) = &self.broadcast_psbt else {
+      return Ok(None);
+    };
+
+    let contents = fs::read_to_string(path)
+      .with_context(|| format!("failed to read PSBT from {}", path.display()))?;
+
+    Ok(Some(wallet.broadcast_psbt(decode(contents.trim())?)?))
+  }
+}
+
+/// Builds a PSBT from an unsigned transaction, populating the witness UTXO and the taproot
+/// internal key, key-origin, and (for a multisig wallet) script-path fields each input needs
+/// before it can be signed offline or by an external signer. `tx`'s inputs must all spend
+/// outputs belonging to `wallet`.
+pub(crate) fn build_unsigned(wallet: &Wallet, tx: &Transaction) -> Result<Psbt> {
+  let mut psbt = Psbt::from_unsigned_tx(tx.clone())?;
+
+  let utxos = wallet.get_unspent_outputs()?;
+
+  for (index, tx_in) in tx.input.iter().enumerate() {
+    let outpoint = tx_in.previous_output;
+
+    let amount = *utxos
+      .get(&outpoint)
+      .ok_or_else(|| anyhow!("input {outpoint} is not a wallet output"))?;
+
+    let script_pubkey = wallet.get_raw_transaction(&outpoint.txid)?.output
+      [usize::try_from(outpoint.vout).unwrap()]
+    .script_pubkey
+    .clone();
+
+    let mut input = Input {
+      witness_utxo: Some(TxOut {
+        value: amount.to_sat(),
+        script_pubkey: script_pubkey.clone(),
+      }),
+      ..Default::default()
+    };
+
+    if let Some(tap_info) = derive_tap_info(wallet, &script_pubkey)? {
+      input.tap_internal_key = Some(tap_info.internal_key);
+      input.tap_key_origins = tap_info.key_origins;
+
+      if let Some((leaf_script, control_block)) = tap_info.leaf_script {
+        input
+          .tap_scripts
+          .insert(control_block, (leaf_script, LeafVersion::TapScript));
+      }
+    }
+
+    psbt.inputs[index] = input;
+  }
+
+  Ok(psbt)
+}
+
+pub(crate) fn encode(psbt: &Psbt) -> String {
+  use base64::Engine;
+  base64::engine::general_purpose::STANDARD.encode(psbt.serialize())
+}
+
+pub(crate) fn decode(s: &str) -> Result<Psbt> {
+  use base64::Engine;
+  Psbt::deserialize(
+    &base64::engine::general_purpose::STANDARD
+      .decode(s)
+      .context("invalid base64 PSBT")?,
+  )
+  .context("invalid PSBT")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn empty_psbt() -> Psbt {
+    Psbt::from_unsigned_tx(Transaction {
+      version: 2,
+      lock_time: bitcoin::absolute::LockTime::ZERO,
+      input: Vec::new(),
+      output: Vec::new(),
+    })
+    .unwrap()
+  }
+
+  #[test]
+  fn encode_decode_roundtrips() {
+    let psbt = empty_psbt();
+    assert_eq!(decode(&encode(&psbt)).unwrap(), psbt);
+  }
+
+  #[test]
+  fn decode_rejects_invalid_base64() {
+    assert!(decode("not valid base64!!!").is_err());
+  }
+
+  #[test]
+  fn tap_output_key_recognizes_p2tr_script() {
+    use bitcoin::{
+      key::TapTweak,
+      secp256k1::{Secp256k1, SecretKey},
+    };
+
+    let secp = Secp256k1::new();
+    let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+    let (key, _parity) = secret_key.keypair(&secp).x_only_public_key();
+
+    let script = ScriptBuf::new_p2tr(&secp, key, None);
+
+    // `new_p2tr` commits to the tweaked output key, not the internal key it was given.
+    let (output_key, _parity) = key.tap_tweak(&secp, None);
+
+    assert_eq!(tap_output_key(&script), Some(output_key.to_inner()));
+  }
+
+  #[test]
+  fn tap_output_key_rejects_non_taproot_script() {
+    assert_eq!(tap_output_key(&ScriptBuf::new()), None);
+  }
+}
@@ -10,11 +10,40 @@ pub(crate) struct Restore {
     help = "Use <PASSPHRASE> when deriving wallet"
   )]
   pub(crate) passphrase: String,
+  #[arg(
+    long,
+    value_name = "K-OF-N",
+    help = "Restore a <K-OF-N> taproot multisig wallet, e.g. `2-of-3`. The mnemonic supplies one key; the remaining n - 1 must be supplied with `--cosigner`."
+  )]
+  pub(crate) multisig: Option<create::Threshold>,
+  #[arg(
+    long,
+    requires = "multisig",
+    value_name = "XPUB",
+    help = "Add <XPUB> (in `[fingerprint/path]xpub...` form) as a multisig cosigner. May be given multiple times."
+  )]
+  pub(crate) cosigner: Vec<DescriptorPublicKey>,
 }
 
 impl Restore {
-  pub(crate) fn run(self, options: Options) -> SubcommandResult {
-    Wallet::initialize_wallet(&options, self.mnemonic.to_seed(self.passphrase))?;
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let seed = self.mnemonic.to_seed(self.passphrase);
+
+    if let Some(threshold) = self.multisig {
+      ensure!(
+        self.cosigner.len() == threshold.n - 1,
+        "`--multisig {}-of-{}` requires {} `--cosigner` keys, got {}",
+        threshold.k,
+        threshold.n,
+        threshold.n - 1,
+        self.cosigner.len(),
+      );
+
+      wallet.initialize_multisig(seed, threshold.k, self.cosigner)?;
+    } else {
+      wallet.initialize(seed)?;
+    }
+
     Ok(Box::new(Empty {}))
   }
 }
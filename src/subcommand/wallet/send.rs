@@ -0,0 +1,54 @@
+use super::*;
+
+#[derive(Debug, Parser, Clone)]
+pub(crate) struct Send {
+  #[arg(help = "Send to <ADDRESS>.")]
+  address: Address<NetworkUnchecked>,
+  #[arg(help = "Send <AMOUNT>.")]
+  amount: Amount,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vbyte.")]
+  fee_rate: FeeRate,
+  #[command(flatten)]
+  psbt: psbt::PsbtOptions,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub txid: Txid,
+}
+
+impl Send {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    if self.psbt.sign(&wallet)? {
+      return Ok(Box::new(Empty {}));
+    }
+
+    if let Some(txid) = self.psbt.broadcast(&wallet)? {
+      return Ok(Box::new(Output { txid }));
+    }
+
+    let address = self.address.require_network(wallet.chain().network())?;
+
+    let unsigned_transaction = TransactionBuilder::new(
+      Target::Value(self.amount),
+      wallet.get_inscriptions()?,
+      wallet.get_unspent_outputs()?,
+      wallet.get_locked_outputs()?,
+      wallet.get_runic_outputs()?,
+      address,
+      [wallet.get_change_address()?, wallet.get_change_address()?],
+      self.fee_rate,
+    )
+    .build_transaction()?;
+
+    if self.psbt.dump(&wallet, &unsigned_transaction)? {
+      return Ok(Box::new(Empty {}));
+    }
+
+    let signed = wallet.sign_psbt(psbt::build_unsigned(&wallet, &unsigned_transaction)?)?;
+
+    let txid = wallet.broadcast_psbt(signed)?;
+
+    Ok(Box::new(Output { txid }))
+  }
+}